@@ -1,14 +1,18 @@
 use std::collections::HashSet;
 
+use rustc_hash::FxHashSet;
+
 use ruff_python_ast as ast;
-use ruff_python_ast::Constant;
-use ruff_python_ast::Expr;
+use ruff_python_ast::{Constant, Expr, Operator, Stmt};
+use ruff_python_semantic::{Binding, BindingId, BindingKind, SemanticModel};
 use ruff_text_size::Ranged;
 
 use ruff_diagnostics::{Diagnostic, Violation};
 use ruff_macros::{derive_message_formats, violation};
 
 use crate::checkers::ast::Checker;
+use crate::registry::Rule;
+use crate::settings::types::PythonVersion;
 
 /// ## What it does
 /// Detects an invalid mode for `open()`
@@ -44,25 +48,154 @@ impl Violation for BadOpenMode {
     }
 }
 
+/// ## What it does
+/// Checks for uses of the universal-newlines `U` mode in calls to `open()` and
+/// friends.
+///
+/// ## Why is this bad?
+/// The `U` mode was deprecated in Python 3.4 and removed in Python 3.11. It is
+/// redundant in any case: universal newlines are enabled by default for text
+/// streams, so the `U` can simply be dropped.
+///
+/// ## Example
+/// ```python
+/// fp = open(file, "rU")
+/// ```
+///
+/// Use instead:
+/// ```python
+/// fp = open(file, "r")
+/// ```
+///
+/// ## References
+/// - [Python documentation: `open`](https://docs.python.org/3/library/functions.html#open)
+#[violation]
+pub struct DeprecatedOpenMode {
+    mode: String,
+    removed: bool,
+}
+
+impl Violation for DeprecatedOpenMode {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let DeprecatedOpenMode { mode, removed } = self;
+        // `U` implies read, so dropping it can leave a mode with no explicit
+        // `r`/`w`/`a`/`x` (e.g. `"Ut"` → `"t"`, `"U"` → `""`), which is itself
+        // invalid. Restore the implied `r` in that case so the suggestion is
+        // always a valid mode.
+        let stripped = mode.replace('U', "");
+        let replacement = if stripped.chars().any(|c| matches!(c, 'r' | 'w' | 'a' | 'x')) {
+            stripped
+        } else {
+            format!("r{stripped}")
+        };
+        if *removed {
+            format!(
+                "`U` mode is no longer supported (removed in Python 3.11); \
+                 use `\"{replacement}\"` instead, as universal newlines are the default"
+            )
+        } else {
+            format!(
+                "`U` mode is deprecated and redundant; \
+                 use `\"{replacement}\"` instead, as universal newlines are the default"
+            )
+        }
+    }
+}
+
 /// W1501
 pub(crate) fn bad_open_mode(checker: &mut Checker, call: &ast::ExprCall) {
-    // TODO: also check `pathlib.open`
-    if checker
-        .semantic()
-        .resolve_call_path(&call.func)
-        .is_some_and(|call_path| matches!(call_path.as_slice(), ["", "open"]))
-    {
-        if let Some(mode_arg) = call.arguments.find_argument("mode", 1) {
-            if let Some(string_value) = str_value(mode_arg) {
-                if !is_valid_file_mode(&string_value) {
-                    checker.diagnostics.push(Diagnostic::new(
-                        BadOpenMode { mode: string_value },
-                        mode_arg.range(),
-                    ));
-                }
-            }
+    let Some(mode_position) = mode_argument_position(checker, call) else {
+        return;
+    };
+    let Some(mode_arg) = call.arguments.find_argument("mode", mode_position) else {
+        return;
+    };
+    let Some(string_value) = str_value(mode_arg, checker.semantic()) else {
+        return;
+    };
+
+    if !is_valid_file_mode(&string_value) {
+        checker.diagnostics.push(Diagnostic::new(
+            BadOpenMode { mode: string_value },
+            mode_arg.range(),
+        ));
+        return;
+    }
+
+    // The `U` mode is combinatorially valid but was deprecated in Python 3.4
+    // and removed in Python 3.11. Every version ruff targets is already past
+    // 3.4, so any supported target flags it as at least deprecated.
+    if string_value.contains('U') && checker.enabled(Rule::DeprecatedOpenMode) {
+        let target_version = checker.settings.target_version;
+        checker.diagnostics.push(Diagnostic::new(
+            DeprecatedOpenMode {
+                mode: string_value,
+                removed: target_version >= PythonVersion::Py311,
+            },
+            mode_arg.range(),
+        ));
+    }
+}
+
+/// Return the positional index of the `mode` argument for a known file opener,
+/// or `None` if `call` isn't one.
+fn mode_argument_position(checker: &Checker, call: &ast::ExprCall) -> Option<usize> {
+    // `Path(...).open(...)` is a method call: `mode` is the first positional
+    // argument because `self` is bound to the receiver, not passed through the
+    // argument list.
+    if let Expr::Attribute(ast::ExprAttribute { value, attr, .. }) = call.func.as_ref() {
+        if attr == "open" && is_pathlib_path(checker.semantic(), value) {
+            return Some(0);
         }
     }
+
+    let call_path = checker.semantic().resolve_call_path(&call.func)?;
+    match call_path.as_slice() {
+        ["", "open"]
+        | ["io", "open"]
+        | ["os", "fdopen"]
+        | ["codecs", "open"]
+        | ["gzip" | "bz2" | "lzma", "open"]
+        | ["pathlib", "Path", "open"] => Some(1),
+        _ => None,
+    }
+}
+
+/// Returns `true` if `expr` is known to construct a `pathlib` path instance,
+/// resolving through a single dominating binding the same way `str_value`
+/// resolves the mode argument, so `p = pathlib.Path(f); p.open(...)` is
+/// recognized and not just the directly-chained `pathlib.Path(f).open(...)`.
+fn is_pathlib_path(semantic: &SemanticModel, expr: &Expr) -> bool {
+    is_pathlib_path_inner(semantic, expr, &mut FxHashSet::default())
+}
+
+fn is_pathlib_path_inner(
+    semantic: &SemanticModel,
+    expr: &Expr,
+    visited: &mut FxHashSet<BindingId>,
+) -> bool {
+    match expr {
+        Expr::Call(ast::ExprCall { func, .. }) => {
+            semantic.resolve_call_path(func).is_some_and(|call_path| {
+                matches!(
+                    call_path.as_slice(),
+                    [
+                        "pathlib",
+                        "Path"
+                            | "PurePath"
+                            | "PosixPath"
+                            | "WindowsPath"
+                            | "PurePosixPath"
+                            | "PureWindowsPath"
+                    ]
+                )
+            })
+        }
+        Expr::Name(name) => resolve_dominating_value(semantic, name, visited)
+            .is_some_and(|value| is_pathlib_path_inner(semantic, value, visited)),
+        _ => false,
+    }
 }
 
 fn is_valid_file_mode(modes: &String) -> bool {
@@ -115,13 +248,124 @@ fn is_valid_file_mode(modes: &String) -> bool {
     true
 }
 
-fn str_value(expr: &Expr) -> Option<String> {
-    // TODO: does not handle when a variable is used as mode
+/// Resolve `expr` to a string literal, performing limited constant propagation.
+///
+/// A bare variable (`mode = "rwx"; open(f, mode)`) is folded when its binding
+/// is the name's only definition in scope, is an unconditional assignment that
+/// dominates the use, and its value is itself resolvable; a binary `+` of two
+/// resolvable string literals (`open(f, "r" + "b")`) is concatenated.
+/// Anything that cannot be resolved this way (f-strings, call results, or
+/// names with multiple, conditional, or forward-referencing assignments)
+/// bails out so we never raise a false positive.
+fn str_value(expr: &Expr, semantic: &SemanticModel) -> Option<String> {
+    str_value_inner(expr, semantic, &mut FxHashSet::default())
+}
+
+/// Return the binding for `name` only when it has exactly one definition in its
+/// scope, so we never fold a conditionally or repeatedly re-bound variable.
+fn single_binding(semantic: &SemanticModel, name: &ast::ExprName) -> Option<BindingId> {
+    let mut bindings = semantic.current_scope().get_all(name.id.as_str());
+    let binding_id = bindings.next()?;
+    if bindings.next().is_some() {
+        return None;
+    }
+    Some(binding_id)
+}
+
+/// Returns `true` if `binding`'s statement is not nested inside a branch that
+/// might not execute - an `if`, `try`, `while`, `for`, `with`, or `match` body.
+/// A single assignment being the only binding for a name in its scope is not
+/// enough to prove it dominates a later use: `if cond: mode = "rwx"` is the
+/// only definition of `mode` in scope, but the branch may never run, so it
+/// must not be folded either.
+fn is_unconditional(semantic: &SemanticModel, binding: &Binding) -> bool {
+    let Some(mut node_id) = binding.source else {
+        return false;
+    };
+    while let Some(parent_id) = semantic.parent_statement_id(node_id) {
+        let is_branch = semantic.statement(parent_id).is_some_and(|stmt| {
+            matches!(
+                stmt,
+                Stmt::If(_)
+                    | Stmt::Try(_)
+                    | Stmt::While(_)
+                    | Stmt::For(_)
+                    | Stmt::With(_)
+                    | Stmt::Match(_)
+            )
+        });
+        if is_branch {
+            return false;
+        }
+        node_id = parent_id;
+    }
+    true
+}
+
+/// Resolve `name` to the right-hand-side expression of its single dominating,
+/// unconditional assignment, recording the binding in `visited` so mutually
+/// referential names (`x = y; y = x`) don't recurse forever. Returns `None`
+/// for anything else: multiple or conditional bindings, forward references,
+/// already-visited bindings, or bindings that aren't a plain `Assign`/
+/// `AnnAssign`.
+fn resolve_dominating_value<'a>(
+    semantic: &'a SemanticModel,
+    name: &ast::ExprName,
+    visited: &mut FxHashSet<BindingId>,
+) -> Option<&'a Expr> {
+    let binding_id = single_binding(semantic, name)?;
+    if !visited.insert(binding_id) {
+        return None;
+    }
+    let binding = semantic.binding(binding_id);
+    if !matches!(binding.kind, BindingKind::Assignment) {
+        return None;
+    }
+    if binding.range().start() >= name.range().start() {
+        return None;
+    }
+    if !is_unconditional(semantic, binding) {
+        return None;
+    }
+    match binding.statement(semantic)? {
+        Stmt::Assign(ast::StmtAssign { value, .. }) => Some(value),
+        Stmt::AnnAssign(ast::StmtAnnAssign {
+            value: Some(value), ..
+        }) => Some(value),
+        _ => None,
+    }
+}
+
+fn str_value_inner(
+    expr: &Expr,
+    semantic: &SemanticModel,
+    visited: &mut FxHashSet<BindingId>,
+) -> Option<String> {
     match expr {
         Expr::Constant(ast::ExprConstant {
             value: Constant::Str(value),
             ..
         }) => Some(value.to_string()),
+        Expr::Name(name) => {
+            // Fold only through a single dominating, unconditional assignment;
+            // see `resolve_dominating_value` for the full set of guards.
+            let value = resolve_dominating_value(semantic, name, visited)?;
+            str_value_inner(value, semantic, visited)
+        }
+        Expr::BinOp(ast::ExprBinOp {
+            left,
+            op: Operator::Add,
+            right,
+            ..
+        }) => {
+            // Each operand gets its own copy of `visited`: the set guards against
+            // cycles along one resolution path, and the same name may legitimately
+            // appear on both sides (`m + m`).
+            Some(
+                str_value_inner(left, semantic, &mut visited.clone())?
+                    + &str_value_inner(right, semantic, &mut visited.clone())?,
+            )
+        }
         _ => None,
     }
 }
@@ -0,0 +1,5 @@
+pub(crate) use bad_open_mode::*;
+pub(crate) use overly_permissive_file_mode::*;
+
+mod bad_open_mode;
+mod overly_permissive_file_mode;
@@ -0,0 +1,121 @@
+use ruff_python_ast as ast;
+use ruff_python_ast::{Constant, Expr};
+use ruff_text_size::Ranged;
+
+use ruff_diagnostics::{Diagnostic, Violation};
+use ruff_macros::{derive_message_formats, violation};
+
+use crate::checkers::ast::Checker;
+
+/// ## What it does
+/// Checks for overly permissive file-permission modes passed to `os.chmod`,
+/// `os.open`, `os.mkdir`, and `os.makedirs`.
+///
+/// ## Why is this bad?
+/// Overly permissive file permissions may allow unintended access and
+/// arbitrary code execution. Granting write access to "other", or creating
+/// world-writable/executable files, exposes them to any user on the system;
+/// the setuid and setgid bits are similarly dangerous.
+///
+/// ## Example
+/// ```python
+/// import os
+///
+/// os.chmod("file", 0o777)
+/// ```
+///
+/// Use instead:
+/// ```python
+/// import os
+///
+/// os.chmod("file", 0o644)
+/// ```
+///
+/// ## References
+/// - [Python documentation: `os.chmod`](https://docs.python.org/3/library/os.html#os.chmod)
+/// - [Python documentation: the `stat` module](https://docs.python.org/3/library/stat.html)
+#[violation]
+pub struct OverlyPermissiveFileMode {
+    mode: String,
+    reason: String,
+}
+
+impl Violation for OverlyPermissiveFileMode {
+    #[derive_message_formats]
+    fn message(&self) -> String {
+        let OverlyPermissiveFileMode { mode, reason } = self;
+        format!("`{mode}` is an overly permissive file mode: {reason}")
+    }
+}
+
+/// PLW... (overly-permissive-file-mode)
+pub(crate) fn overly_permissive_file_mode(checker: &mut Checker, call: &ast::ExprCall) {
+    let Some(position) = mode_argument_position(checker, call) else {
+        return;
+    };
+    let Some(mode_arg) = call.arguments.find_argument("mode", position) else {
+        return;
+    };
+
+    // Only reason about literal (octal) integers; skip anything we cannot
+    // decode statically rather than guessing.
+    let Expr::Constant(ast::ExprConstant {
+        value: Constant::Int(value),
+        ..
+    }) = mode_arg
+    else {
+        return;
+    };
+    let Some(mode) = value.as_u32() else {
+        return;
+    };
+
+    let reason = permissive_reasons(mode);
+    if reason.is_empty() {
+        return;
+    }
+
+    checker.diagnostics.push(Diagnostic::new(
+        OverlyPermissiveFileMode {
+            mode: format!("{mode:#o}"),
+            reason: reason.join("; "),
+        },
+        mode_arg.range(),
+    ));
+}
+
+/// Return the positional index of the `mode` argument for the permission-setting
+/// `os` functions, or `None` if `call` isn't one of them.
+fn mode_argument_position(checker: &Checker, call: &ast::ExprCall) -> Option<usize> {
+    let call_path = checker.semantic().resolve_call_path(&call.func)?;
+    match call_path.as_slice() {
+        ["os", "chmod" | "mkdir" | "makedirs"] => Some(1),
+        ["os", "open"] => Some(2),
+        _ => None,
+    }
+}
+
+/// Decode the low 12 bits of a Unix permission mode and describe any bits that
+/// grant dangerously broad access: write access for "other" (`0o002`), the
+/// fully-open `0o777` case, and the setuid/setgid bits. Only "other"'s write
+/// bit is inspected individually - a bare `o+x` (e.g. the common `0o755`/`0o711`
+/// directory modes) is not itself a permission escalation, so it isn't flagged
+/// on its own.
+fn permissive_reasons(mode: u32) -> Vec<String> {
+    let mut reasons = Vec::new();
+
+    if mode & 0o002 != 0 {
+        reasons.push("grants write access to others (`o+w`)".to_string());
+    }
+    if mode & 0o777 == 0o777 {
+        reasons.push("world-readable, -writable, and -executable (`0o777`)".to_string());
+    }
+    if mode & 0o4000 != 0 {
+        reasons.push("sets the setuid bit (`0o4000`)".to_string());
+    }
+    if mode & 0o2000 != 0 {
+        reasons.push("sets the setgid bit (`0o2000`)".to_string());
+    }
+
+    reasons
+}